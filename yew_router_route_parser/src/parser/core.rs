@@ -12,11 +12,32 @@ use crate::parser::CaptureVariant;
 use crate::parser::CaptureOrMatch;
 use nom::error::ParseError;
 
+/// Describes a constraint placed on a named capture, restricting the shape of text it is
+/// allowed to match.
+///
+/// This crate only parses the constraint out of the matcher string and carries it in
+/// [`CaptureVariant::NamedTyped`]; it does not itself validate a matched segment against it.
+/// That's the responsibility of whatever performs real route matching downstream (outside
+/// this crate) - `{id:u32}` parses successfully, but as shipped here matches exactly the same
+/// segments a bare `{id}` would until a matcher consults this field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// Matches one or more digits.
+    Integer,
+    /// Matches one or more alphabetic characters.
+    Alpha,
+    /// Matches one or more alphanumeric characters.
+    AlphaNumeric,
+    /// Matches a UUID.
+    Uuid,
+    /// Matches an arbitrary regex, written between slashes: `{name:/[0-9a-f]+/}`.
+    Regex(String),
+}
 
 /// Captures a string up to the point where a character not possible to be present in Rust's identifier is encountered.
 /// It prevents the first character from being a digit.
 pub fn valid_ident_characters(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
-    const INVALID_CHARACTERS: &str = " -*/+#?&^@~`;,.|\\{}[]()=\t\n";
+    const INVALID_CHARACTERS: &str = " -*/+#?&^@~`:;,.|\\{}[]()=\t\n";
     context(
         "valid ident",
         |i: &str| {
@@ -39,6 +60,23 @@ pub fn match_specific(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&
 }
 
 
+/// Matches a type constraint following a named capture's `:`.
+///
+/// * u32
+/// * alpha
+/// * alphanum
+/// * uuid
+/// * /regex/
+pub fn constraint(i: &str) -> IResult<&str, Constraint, VerboseError<&str>> {
+    context("constraint", alt((
+        map(delimited(tag("/"), is_not("/"), tag("/")), |s: &str| Constraint::Regex(s.to_string())),
+        map(tag("u32"), |_| Constraint::Integer),
+        map(tag("alphanum"), |_| Constraint::AlphaNumeric),
+        map(tag("alpha"), |_| Constraint::Alpha),
+        map(tag("uuid"), |_| Constraint::Uuid),
+    )))(i)
+}
+
 /// Matches any of the capture variants
 ///
 /// * {}
@@ -47,12 +85,15 @@ pub fn match_specific(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&
 /// * {name}
 /// * {*:name}
 /// * {5:name}
+/// * {name:u32}
+/// * {name:/[0-9a-f]+/}
 pub fn capture(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
     let capture_variants = alt(
         (
             map(peek(tag("}")), |_| CaptureVariant::Unnamed), // just empty {}
             map(preceded(tag("*:"), valid_ident_characters), |s| CaptureVariant::ManyNamed(s.to_string())),
             map(tag("*"), |_| CaptureVariant::ManyUnnamed),
+            map(separated_pair(valid_ident_characters, tag(":"), constraint), |(name, constraint)| CaptureVariant::NamedTyped {name: name.to_string(), constraint}),
             map(valid_ident_characters, |s| CaptureVariant::Named(s.to_string())),
             map(separated_pair(digit1, tag(":"), valid_ident_characters), |(n, s)| CaptureVariant::NumberedNamed {sections: n.parse().expect("Should parse digits"), name: s.to_string()}),
             map(digit1, |num: &str| CaptureVariant::NumberedUnnamed {sections: num.parse().expect("should parse digits" )})
@@ -140,4 +181,40 @@ mod test {
         capture("{aoeu").expect_err("Should not complete");
     }
 
+    #[test]
+    fn capture_named_typed_integer() {
+        let cap = capture("{id:u32}").unwrap();
+        assert_eq!(cap, ("", RouteParserToken::Capture (CaptureVariant::NamedTyped {name: "id".to_string(), constraint: Constraint::Integer})));
+    }
+
+    #[test]
+    fn capture_named_typed_alpha() {
+        let cap = capture("{slug:alpha}").unwrap();
+        assert_eq!(cap, ("", RouteParserToken::Capture (CaptureVariant::NamedTyped {name: "slug".to_string(), constraint: Constraint::Alpha})));
+    }
+
+    #[test]
+    fn capture_named_typed_alphanumeric() {
+        let cap = capture("{slug:alphanum}").unwrap();
+        assert_eq!(cap, ("", RouteParserToken::Capture (CaptureVariant::NamedTyped {name: "slug".to_string(), constraint: Constraint::AlphaNumeric})));
+    }
+
+    #[test]
+    fn capture_named_typed_uuid() {
+        let cap = capture("{uid:uuid}").unwrap();
+        assert_eq!(cap, ("", RouteParserToken::Capture (CaptureVariant::NamedTyped {name: "uid".to_string(), constraint: Constraint::Uuid})));
+    }
+
+    #[test]
+    fn capture_named_typed_regex() {
+        let cap = capture("{name:/[0-9a-f]+/}").unwrap();
+        assert_eq!(cap, ("", RouteParserToken::Capture (CaptureVariant::NamedTyped {name: "name".to_string(), constraint: Constraint::Regex("[0-9a-f]+".to_string())})));
+    }
+
+    #[test]
+    fn numbered_named_still_disambiguates_from_typed() {
+        let cap = capture("{5:name}").unwrap();
+        assert_eq!(cap, ("", RouteParserToken::Capture (CaptureVariant::NumberedNamed {sections: 5, name: "name".to_string()})));
+    }
+
 }
\ No newline at end of file
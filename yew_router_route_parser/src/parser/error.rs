@@ -0,0 +1,108 @@
+//! Human-readable diagnostics for route matcher parse failures.
+use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+
+/// A single frame of a parse failure, with its column computed from how much of the matcher
+/// string had already been consumed when the error occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteParseError {
+    /// The column (byte offset into the matcher string) where this frame's error begins.
+    pub column: usize,
+    /// A human-readable message describing what went wrong.
+    pub message: String,
+}
+
+/// Renders a [`VerboseError`] produced while parsing `matcher` into a human-readable message,
+/// printing the matcher string with a `^` caret under the offending column.
+///
+/// The most specific frame - the one with the smallest remainder, and therefore the largest
+/// column - drives the primary message, since it represents the parser's deepest attempt
+/// before giving up.
+pub fn render_parse_error(matcher: &str, err: VerboseError<&str>) -> String {
+    let frames: Vec<RouteParseError> = err.errors.iter()
+        .map(|(remainder, kind)| RouteParseError {
+            column: matcher.len() - remainder.len(),
+            message: frame_message(kind, matcher.len() - remainder.len(), remainder),
+        })
+        .collect();
+
+    let primary = frames.iter()
+        .max_by_key(|frame| frame.column)
+        .expect("VerboseError should carry at least one frame");
+
+    let caret_line: String = (0..primary.column).map(|_| ' ').chain(std::iter::once('^')).collect();
+
+    format!("{}\n{}\n{}", matcher, caret_line, primary.message)
+}
+
+/// Maps a single error frame's context/kind to an actionable message.
+///
+/// `remainder` is the unconsumed input this frame's error was raised against. It's needed
+/// because `all_consuming` reports a bare `Nom(Eof)` - with no surrounding `Context` frame -
+/// when `section_matchers` parses successfully but stops early because two matching sections
+/// (e.g. `{match1}{match2}`) can't be told apart; the only way to recognize that case is to
+/// notice the leftover input itself starts a new capture.
+fn frame_message(kind: &VerboseErrorKind, column: usize, remainder: &str) -> String {
+    match kind {
+        VerboseErrorKind::Context("section matchers") => format!(
+            "expected a separator or literal between `{{..}}` captures at column {}", column
+        ),
+        VerboseErrorKind::Context("capture") => format!(
+            "expected a capture such as `{{name}}` at column {}", column
+        ),
+        VerboseErrorKind::Context(ctx) => format!("expected {} at column {}", ctx, column),
+        VerboseErrorKind::Char(c) => format!("expected the character `{}` at column {}", c, column),
+        VerboseErrorKind::Nom(ErrorKind::Eof) if remainder.starts_with('{') => format!(
+            "expected a separator or literal between `{{..}}` captures at column {}", column
+        ),
+        VerboseErrorKind::Nom(kind) => format!("failed to parse ({:?}) at column {}", kind, column),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::error::{ErrorKind, VerboseErrorKind::{Nom, Context}};
+
+    #[test]
+    fn renders_caret_under_offending_column() {
+        let matcher = "/path{}{match}";
+        let err = VerboseError {
+            errors: vec![
+                ("{match}", Nom(ErrorKind::Eof)),
+            ],
+        };
+        let rendered = render_parse_error(matcher, err);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], matcher);
+        assert_eq!(lines[1], "       ^");
+    }
+
+    #[test]
+    fn uses_most_specific_frame_as_primary() {
+        let matcher = "{aoeu";
+        let err = VerboseError {
+            errors: vec![
+                ("", Nom(ErrorKind::Tag)),
+                ("{aoeu", Context("capture")),
+                ("{aoeu", Nom(ErrorKind::Alt)),
+                ("{aoeu", Context("section matchers")),
+            ],
+        };
+        let rendered = render_parse_error(matcher, err);
+        assert!(rendered.ends_with("failed to parse (Tag) at column 5"));
+    }
+
+    #[test]
+    fn double_capture_gets_actionable_message() {
+        // This is the exact VerboseError `all_consuming(path_parser)` produces for
+        // "/path{match1}{match2}" - see path.rs's own
+        // `path_cant_contain_multiple_matches_in_a_row_1` test.
+        let matcher = "/path{match1}{match2}";
+        let err = VerboseError {
+            errors: vec![("{match2}", Nom(ErrorKind::Eof))],
+        };
+        let rendered = render_parse_error(matcher, err);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[2], "expected a separator or literal between `{..}` captures at column 13");
+    }
+}
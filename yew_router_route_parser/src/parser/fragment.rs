@@ -0,0 +1,56 @@
+//! Parsers for the fragment portion of a matcher string.
+use nom::IResult;
+use nom::combinator::map;
+use nom::sequence::pair;
+use nom::bytes::complete::tag;
+use nom::error::{VerboseError, context};
+use crate::parser::RouteParserToken;
+use crate::parser::path::section_matchers;
+
+/// Parses the fragment section of a matcher string.
+///
+/// * #section
+/// * #{capture}
+/// * #section{capture}
+pub fn fragment_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
+    context("fragment", map(
+        pair(fragment_begin, section_matchers),
+        |(begin, mut sections)| {
+            let mut tokens = vec![begin];
+            tokens.append(&mut sections);
+            tokens
+        }
+    ))(i)
+}
+
+fn fragment_begin(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
+    context("#", map(tag("#"), |_| RouteParserToken::FragmentBegin))(i)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::CaptureVariant;
+
+    #[test]
+    fn fragment_begin_test() {
+        fragment_begin("#").expect("Should parse");
+    }
+
+    #[test]
+    fn literal_fragment() {
+        let (_, tokens) = fragment_parser("#section").expect("Should parse");
+        assert_eq!(tokens, vec![RouteParserToken::FragmentBegin, RouteParserToken::Match("section".to_string())]);
+    }
+
+    #[test]
+    fn captured_fragment() {
+        let (_, tokens) = fragment_parser("#{section}").expect("Should parse");
+        assert_eq!(tokens, vec![RouteParserToken::FragmentBegin, RouteParserToken::Capture(CaptureVariant::Named("section".to_string()))]);
+    }
+
+    #[test]
+    fn rejects_missing_hash() {
+        fragment_parser("section").expect_err("Should reject at absence of #");
+    }
+}
@@ -18,21 +18,6 @@ use crate::parser::util::optional_matches;
 /// * (/item)
 /// * (/item)(/item) and so on
 pub fn path_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
-    fn inner_path_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
-        context("/ and item",
-            map(
-            pair(
-                separator_token,
-                section_matchers
-            ),
-            |(sep, mut sections)| {
-                let mut x = vec![sep];
-                x.append(&mut sections);
-                x
-            }
-        ))(i)
-    }
-
     // /item/item/item
     let many_inner_paths = context(
         "many inner paths",
@@ -80,13 +65,29 @@ pub fn path_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError
 }
 
 
-fn separator_token(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
+pub(crate) fn separator_token(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
     context("/", map(
         tag("/"),
         |_| RouteParserToken::Separator
     ))(i)
 }
 
+/// Matches a single `/item` section: a separator followed by its section matchers.
+pub(crate) fn inner_path_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
+    context("/ and item",
+        map(
+        pair(
+            separator_token,
+            section_matchers
+        ),
+        |(sep, mut sections)| {
+            let mut x = vec![sep];
+            x.append(&mut sections);
+            x
+        }
+    ))(i)
+}
+
 
 pub fn section_matchers(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
 
@@ -0,0 +1,90 @@
+//! Parsers for the query-string portion of a matcher string.
+use nom::IResult;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom::sequence::{pair, tuple};
+use nom::bytes::complete::tag;
+use nom::error::{VerboseError, context};
+use crate::parser::RouteParserToken;
+use crate::parser::core::{capture_or_match, valid_ident_characters};
+
+/// Parses the query section of a matcher string.
+///
+/// * ?key=value
+/// * ?key={capture}
+/// * ?key=value&key2={capture}
+/// * ?key={capture}&key2={capture2}&key3=value
+///
+/// The order the keys are written in the matcher string has no bearing on the order they need
+/// to appear in at match time - the query string is matched as an unordered set of key/value
+/// pairs.
+pub fn query_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
+    context("query", map(
+        tuple((query_begin, query_capture, many0(pair(query_separator, query_capture)))),
+        |(begin, first, rest)| {
+            let mut tokens = vec![begin, first];
+            for (sep, capture) in rest {
+                tokens.push(sep);
+                tokens.push(capture);
+            }
+            tokens
+        }
+    ))(i)
+}
+
+fn query_begin(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
+    context("?", map(tag("?"), |_| RouteParserToken::QueryBegin))(i)
+}
+
+fn query_separator(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
+    context("&", map(tag("&"), |_| RouteParserToken::QuerySeparator))(i)
+}
+
+/// Matches a single `key={capture}` or `key=literal` pair.
+fn query_capture(i: &str) -> IResult<&str, RouteParserToken, VerboseError<&str>> {
+    context("key=value", map(
+        tuple((valid_ident_characters, tag("="), capture_or_match)),
+        |(ident, _, capture_or_match)| RouteParserToken::QueryCapture { ident: ident.to_string(), capture_or_match }
+    ))(i)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::{CaptureVariant, CaptureOrMatch};
+
+    #[test]
+    fn query_begin_test() {
+        query_begin("?").expect("Should parse");
+    }
+
+    #[test]
+    fn single_key_value() {
+        let (_, tokens) = query_parser("?key=value").expect("Should parse");
+        assert_eq!(tokens, vec![
+            RouteParserToken::QueryBegin,
+            RouteParserToken::QueryCapture { ident: "key".to_string(), capture_or_match: CaptureOrMatch::Match("value".to_string()) }
+        ]);
+    }
+
+    #[test]
+    fn single_key_capture() {
+        let (_, tokens) = query_parser("?key={value}").expect("Should parse");
+        assert_eq!(tokens, vec![
+            RouteParserToken::QueryBegin,
+            RouteParserToken::QueryCapture { ident: "key".to_string(), capture_or_match: CaptureOrMatch::Capture(CaptureVariant::Named("value".to_string())) }
+        ]);
+    }
+
+    #[test]
+    fn multiple_keys() {
+        let (_, tokens) = query_parser("?term={query}&page={page}").expect("Should parse");
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(tokens[2], RouteParserToken::QuerySeparator);
+    }
+
+    #[test]
+    fn rejects_missing_question_mark() {
+        query_parser("key=value").expect_err("Should reject at absence of ?");
+    }
+}
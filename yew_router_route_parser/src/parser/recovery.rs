@@ -0,0 +1,182 @@
+//! Error-recovery parsing that collects every diagnostic in a matcher string instead of
+//! bailing out at the first mistake.
+use crate::parser::RouteParserToken;
+use crate::parser::error::RouteParseError;
+use crate::parser::path::path_parser;
+use crate::parser::query::query_parser;
+use crate::parser::fragment::fragment_parser;
+
+/// Parses `matcher`, recovering from failures instead of aborting at the first one.
+///
+/// Each iteration tries a single [`route_prefix`] - a path, optionally followed by a query
+/// and/or fragment, mirroring `route_parser` - so this understands the full grammar the rest
+/// of this series ships (optional `(/item)` sections, `?key=value` queries, `#fragment`s), not
+/// just the bare path grammar. On a failed or zero-progress attempt, a
+/// `RouteParserToken::Error` placeholder is pushed in place of the broken section, the
+/// corresponding diagnostic is recorded, and parsing resumes at the next `/`, `{`, or `}`
+/// boundary. Recovery always consumes at least one byte, so a matcher that is nothing but
+/// garbage still terminates.
+///
+/// Note: once a [`route_prefix`] attempt has consumed input, the leftover remainder is *not*
+/// retried as a fresh route prefix until a recovery skip happens first. This is deliberate: a
+/// lone trailing `/` is valid syntax on its own (see `path_parser`'s trailing-separator
+/// handling), so retrying unconditionally would let something like `//` parse as two valid
+/// root paths back to back instead of flagging the repeated separator as a mistake. The
+/// trade-off is that the error chunk recorded for a run like `/a//b` may swallow a character
+/// or two of otherwise-valid text following the mistake (e.g. the `b` in `/a//b`) rather than
+/// recovering it - still a loud, correctly-located diagnostic, just not a maximally precise one.
+pub fn parse_recovering(matcher: &str) -> (Vec<RouteParserToken>, Vec<RouteParseError>) {
+    let mut tokens = Vec::new();
+    let mut errors = Vec::new();
+    let mut i = matcher;
+    let mut route_prefix_allowed = true;
+
+    while !i.is_empty() {
+        if route_prefix_allowed {
+            if let Some((rest, mut new_tokens)) = route_prefix(i) {
+                if rest.len() < i.len() {
+                    tokens.append(&mut new_tokens);
+                    i = rest;
+                    route_prefix_allowed = false;
+                    continue;
+                }
+            }
+        }
+
+        recover(matcher, &mut i, &mut tokens, &mut errors);
+        route_prefix_allowed = true;
+    }
+
+    (tokens, errors)
+}
+
+/// Tries to parse as much of `i` as a path, optionally followed by a query and/or a fragment -
+/// the same grammar `route_parser` composes, but tolerant of `i` being only a path, or the
+/// query/fragment parsers failing, so recovery can make partial progress instead of an
+/// all-or-nothing match.
+fn route_prefix(i: &str) -> Option<(&str, Vec<RouteParserToken>)> {
+    let (i, mut tokens) = path_parser(i).ok()?;
+
+    let i = match query_parser(i) {
+        Ok((rest, mut query_tokens)) => {
+            tokens.append(&mut query_tokens);
+            rest
+        }
+        Err(_) => i,
+    };
+
+    let i = match fragment_parser(i) {
+        Ok((rest, mut fragment_tokens)) => {
+            tokens.append(&mut fragment_tokens);
+            rest
+        }
+        Err(_) => i,
+    };
+
+    Some((i, tokens))
+}
+
+/// Records a diagnostic for the input remaining at `*i` and advances `*i` to the next
+/// recovery boundary, always consuming at least one byte.
+fn recover<'a>(matcher: &str, i: &mut &'a str, tokens: &mut Vec<RouteParserToken>, errors: &mut Vec<RouteParseError>) {
+    let column = matcher.len() - i.len();
+    let (error_text, rest) = recover_to_boundary(i);
+    errors.push(RouteParseError {
+        column,
+        message: format!("unexpected input `{}` at column {}", error_text, column),
+    });
+    tokens.push(RouteParserToken::Error(error_text.to_string()));
+    *i = rest;
+}
+
+/// Skips forward to the next `/`, `{`, or `}` boundary, always consuming at least one byte so
+/// recovery is guaranteed to make forward progress.
+fn recover_to_boundary(i: &str) -> (&str, &str) {
+    let mut chars = i.char_indices();
+    chars.next(); // always consume at least one byte to guarantee forward progress
+    let boundary = chars
+        .find(|(_, c)| matches!(c, '/' | '{' | '}'))
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| i.len());
+    i.split_at(boundary)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recovers_past_a_bad_section() {
+        let (tokens, errors) = parse_recovering("/item/ /other");
+        assert_eq!(errors.len(), 1);
+        assert!(tokens.contains(&RouteParserToken::Match("item".to_string())));
+        assert!(tokens.contains(&RouteParserToken::Match("other".to_string())));
+    }
+
+    #[test]
+    fn collects_every_diagnostic_instead_of_stopping_at_the_first() {
+        let (_, errors) = parse_recovering("/ /good/ /");
+        assert!(errors.len() >= 2);
+    }
+
+    #[test]
+    fn always_makes_forward_progress() {
+        let (tokens, errors) = parse_recovering("{{{");
+        assert!(!tokens.is_empty() || !errors.is_empty());
+    }
+
+    #[test]
+    fn valid_matcher_produces_no_errors() {
+        let (_, errors) = parse_recovering("/item/{id}");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn root_separator_is_not_an_error() {
+        let (tokens, errors) = parse_recovering("/");
+        assert_eq!(tokens, vec![RouteParserToken::Separator]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn trailing_separator_is_not_an_error() {
+        let (tokens, errors) = parse_recovering("/item/");
+        assert_eq!(tokens, vec![
+            RouteParserToken::Separator,
+            RouteParserToken::Match("item".to_string()),
+            RouteParserToken::Separator,
+        ]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn double_separator_is_flagged_as_an_error() {
+        // Matches path_parser's own `cant_have_double_slash` expectation: a repeated `/` with
+        // nothing in between is not valid syntax, and recovery must not silently accept it.
+        let (_, errors) = parse_recovering("//");
+        assert_eq!(errors.len(), 1);
+
+        let (_, errors) = parse_recovering("/a//b");
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn optional_section_produces_no_errors() {
+        // Matches path_parser's own `option_section` expectation.
+        let (_, errors) = parse_recovering("/hello(/hello)");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn query_string_produces_no_errors() {
+        // Matches chunk0-1's route_parser expectation that a query string is valid syntax.
+        let (_, errors) = parse_recovering("/item?key=value");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn fragment_produces_no_errors() {
+        let (_, errors) = parse_recovering("/item#section");
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+//! Top-level parser composing the path, query, and fragment grammars.
+use nom::IResult;
+use nom::combinator::{map, opt};
+use nom::sequence::tuple;
+use nom::error::{VerboseError, context};
+use crate::parser::RouteParserToken;
+use crate::parser::path::path_parser;
+use crate::parser::query::query_parser;
+use crate::parser::fragment::fragment_parser;
+
+/// Parses a full matcher string: a path, followed by an optional query, followed by an
+/// optional fragment.
+///
+/// * /path
+/// * /path?query={capture}
+/// * /path#fragment
+/// * /path?term={query}&page={page}#{section}
+pub fn route_parser(i: &str) -> IResult<&str, Vec<RouteParserToken>, VerboseError<&str>> {
+    context("route", map(
+        tuple((path_parser, opt(query_parser), opt(fragment_parser))),
+        |(mut path, query, fragment)| {
+            if let Some(mut query) = query {
+                path.append(&mut query);
+            }
+            if let Some(mut fragment) = fragment {
+                path.append(&mut fragment);
+            }
+            path
+        }
+    ))(i)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn path_only() {
+        route_parser("/item").expect("Should parse");
+    }
+
+    #[test]
+    fn path_and_query() {
+        route_parser("/item?key={value}").expect("Should parse");
+    }
+
+    #[test]
+    fn path_and_fragment() {
+        route_parser("/item#{section}").expect("Should parse");
+    }
+
+    #[test]
+    fn path_query_and_fragment() {
+        let (rest, _) = route_parser("/search?term={query}&page={page}#{section}").expect("Should parse");
+        assert_eq!(rest, "");
+    }
+}
@@ -0,0 +1,87 @@
+//! Span-annotated tokens for tooling (proc-macro diagnostics, editor integrations) that need
+//! to point at *where* in a matcher string a token came from.
+use nom::IResult;
+use nom::branch::alt;
+use nom::combinator::all_consuming;
+use nom::multi::many1;
+use nom::error::VerboseError;
+use crate::parser::RouteParserToken;
+use crate::parser::core::{capture, match_specific};
+use crate::parser::path::{path_parser, separator_token};
+
+/// A [`RouteParserToken`] together with the byte range in the original matcher string it was
+/// parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned<T> {
+    pub token: T,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Wraps a token-producing parser so that it also records the `(start, end)` byte offsets it
+/// consumed, computed by diffing the length of the remainder before and after the sub-parser
+/// runs against the total length of the matcher string.
+///
+/// Takes `f` by `Fn`, not `FnMut`: `separator_token`/`match_specific`/`capture` are plain
+/// functions, and `alt`/`many1`/`all_consuming` in this nom version all require their branches
+/// to implement `Fn`, not just `FnMut`.
+fn spanned<'a, O>(
+    total_len: usize,
+    f: impl Fn(&'a str) -> IResult<&'a str, O, VerboseError<&'a str>>,
+) -> impl Fn(&'a str) -> IResult<&'a str, Spanned<O>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        let start = total_len - i.len();
+        let (rest, token) = f(i)?;
+        let end = total_len - rest.len();
+        Ok((rest, Spanned { token, start, end }))
+    }
+}
+
+/// Parses a matcher string's separators, matches, and captures the same way
+/// [`crate::parser::path::path_parser`] does, annotating every token with the byte range it
+/// came from so a consumer can underline the exact offending capture or separator.
+///
+/// This does not yet understand `path_parser`'s optional-section grouping (`(/item)`). Rather
+/// than silently stopping at the `(` and handing back a truncated, wrong span list, this is
+/// wrapped in [`all_consuming`] so a matcher containing an optional section fails loudly
+/// instead.
+pub fn parse_spanned(matcher: &str) -> IResult<&str, Vec<Spanned<RouteParserToken>>, VerboseError<&str>> {
+    // Reject anything `path_parser` rejects (e.g. two captures in a row, `//`) before spanning.
+    all_consuming(path_parser)(matcher)?;
+    let total_len = matcher.len();
+    all_consuming(many1(alt((
+        spanned(total_len, separator_token),
+        spanned(total_len, match_specific),
+        spanned(total_len, capture),
+    ))))(matcher)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::CaptureVariant;
+
+    #[test]
+    fn spans_separator() {
+        let (_, tokens) = parse_spanned("/").expect("Should parse");
+        assert_eq!(tokens, vec![Spanned { token: RouteParserToken::Separator, start: 0, end: 1 }]);
+    }
+
+    #[test]
+    fn spans_match_and_capture() {
+        let (_, tokens) = parse_spanned("/item/{id}").expect("Should parse");
+        assert_eq!(tokens, vec![
+            Spanned { token: RouteParserToken::Separator, start: 0, end: 1 },
+            Spanned { token: RouteParserToken::Match("item".to_string()), start: 1, end: 5 },
+            Spanned { token: RouteParserToken::Separator, start: 5, end: 6 },
+            Spanned { token: RouteParserToken::Capture(CaptureVariant::Named("id".to_string())), start: 6, end: 10 },
+        ]);
+    }
+
+    #[test]
+    fn rejects_optional_sections_instead_of_truncating_spans() {
+        // `(/item)` groups aren't understood yet; failing loudly beats silently handing back
+        // a truncated span list that stops at the `(`.
+        parse_spanned("/hello(/hello)").expect_err("optional sections are not yet span-aware");
+    }
+}